@@ -1,6 +1,8 @@
 use crate::ascii_images;
 use crossterm::event;
 use ratatui::{layout, style::Stylize, symbols, text, widgets, DefaultTerminal, Frame};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -9,19 +11,310 @@ use std::time;
 
 const DURATION_PRESETS: [u64; 11] = [2, 3, 4, 5, 10, 15, 20, 25, 30, 45, 60];
 
+/// Interval of the input/tick loop, in milliseconds.
+const TICK_MS: u64 = 200;
+
+/// Languages the UI can be displayed in: `(code, native display name)`. The
+/// code selects a bundled string table in [`I18n::load`].
+const LANGUAGES: [(&str, &str); 2] = [("en", "English"), ("es", "Español")];
+
+/// Active translation table: a flat `key -> string` map loaded from one of the
+/// bundled `i18n/*.toml` files. Unknown keys fall back to the key itself so a
+/// missing translation is visible rather than blank.
+struct I18n {
+    strings: HashMap<String, String>,
+}
+
+impl I18n {
+    fn load(code: &str) -> Self {
+        let data = match code {
+            "es" => include_str!("i18n/es.toml"),
+            _ => include_str!("i18n/en.toml"),
+        };
+        let strings = toml::from_str(data).unwrap_or_default();
+        I18n { strings }
+    }
+
+    fn get(&self, key: &str) -> &str {
+        self.strings.get(key).map(String::as_str).unwrap_or(key)
+    }
+}
+
+/// Persisted user settings, stored as TOML under the platform config dir so
+/// choices made in the Configuration Menu survive across runs. CLI arguments
+/// are used only while no readable config file exists (it is first written
+/// when a setting is changed in a menu); once it exists the file wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    work_min: u64,
+    break_min: u64,
+    sound: PathBuf,
+    auto_start: bool,
+    #[serde(default = "default_volume")]
+    volume: u8,
+    #[serde(default)]
+    muted: bool,
+    #[serde(default)]
+    work_ambience: Option<PathBuf>,
+    #[serde(default)]
+    break_ambience: Option<PathBuf>,
+    #[serde(default = "default_language")]
+    language: String,
+}
+
+fn default_volume() -> u8 {
+    100
+}
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+impl Config {
+    /// Location of the config file, e.g. `~/.config/pomodoro-tui/config.toml`.
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("pomodoro-tui").join("config.toml"))
+    }
+
+    /// Read the persisted settings, or `None` if the file is missing or
+    /// cannot be parsed (in which case the caller falls back to CLI defaults).
+    fn load() -> Option<Config> {
+        let contents = fs::read_to_string(Config::path()?).ok()?;
+        toml::from_str(&contents).ok()
+    }
+
+    /// Write the current settings back to disk, creating the config directory
+    /// if needed. Errors are swallowed: a failed save must not interrupt the
+    /// running timer.
+    fn save(&self) {
+        let Some(path) = Config::path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(contents) = toml::to_string_pretty(self) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    /// Linear gain passed to the rodio sink: `0.0` when muted, otherwise the
+    /// stored percentage mapped onto `0.0..=1.0`.
+    fn effective_volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume as f32 / 100.0
+        }
+    }
+}
+
+/// How much a single Left/Right press changes the volume.
+const VOLUME_STEP: u8 = 5;
+
 enum Event {
     Key(event::KeyEvent),
     Tick,
 }
 
+/// Whether a menu entry can be highlighted and selected, or is a
+/// non-selectable header that navigation skips over.
+#[derive(Debug, PartialEq)]
+enum EntryKind {
+    Active,
+    Disabled,
+}
+
+/// A single row in a [`Menu`]: the typed value it represents, the label
+/// shown in the popup, and whether it is selectable.
+#[derive(Debug, PartialEq)]
+struct MenuEntry<T> {
+    value: T,
+    label: String,
+    kind: EntryKind,
+}
+
+impl<T> MenuEntry<T> {
+    fn active(value: T, label: impl Into<String>) -> Self {
+        MenuEntry {
+            value,
+            label: label.into(),
+            kind: EntryKind::Active,
+        }
+    }
+}
+
+/// Generic list-style menu parameterized by a per-screen entry type.
+///
+/// Owns its entries and tracks the highlighted one by position, skipping
+/// [`EntryKind::Disabled`] rows during navigation so the caller never has to
+/// reason about item counts or magic indices.
 #[derive(Debug, PartialEq)]
+struct Menu<T> {
+    entries: Vec<MenuEntry<T>>,
+    highlighted: usize,
+}
+
+impl<T> Menu<T> {
+    fn new(entries: Vec<MenuEntry<T>>) -> Self {
+        let highlighted = entries
+            .iter()
+            .position(|e| e.kind == EntryKind::Active)
+            .unwrap_or(0);
+        Menu {
+            entries,
+            highlighted,
+        }
+    }
+
+    fn up(&mut self) {
+        for i in (0..self.highlighted).rev() {
+            if self.entries[i].kind == EntryKind::Active {
+                self.highlighted = i;
+                return;
+            }
+        }
+    }
+
+    fn down(&mut self) {
+        for i in (self.highlighted + 1)..self.entries.len() {
+            if self.entries[i].kind == EntryKind::Active {
+                self.highlighted = i;
+                return;
+            }
+        }
+    }
+
+    /// The value of the highlighted entry, or `None` if it is not selectable.
+    fn selected(&self) -> Option<&T> {
+        self.entries
+            .get(self.highlighted)
+            .filter(|e| e.kind == EntryKind::Active)
+            .map(|e| &e.value)
+    }
+}
+
+impl<T: PartialEq> Menu<T> {
+    /// Move the highlight onto the entry holding `value`, if present.
+    fn highlight(&mut self, value: &T) {
+        if let Some(i) = self.entries.iter().position(|e| &e.value == value) {
+            self.highlighted = i;
+        }
+    }
+}
+
+/// Entries of the top-level configuration menu.
+#[derive(Debug, PartialEq)]
+enum MainMenuEntry {
+    WorkDuration,
+    BreakDuration,
+    AutoStart,
+    Sound,
+    Language,
+    Back,
+}
+
+/// Entries of the "extend work session" prompt: either extend by a preset
+/// number of minutes, or decline and start the break.
+#[derive(Debug, PartialEq)]
+enum ExtendEntry {
+    Extend(u64),
+    StartBreak,
+}
+
+/// Entries of the Sound submenu.
+#[derive(Debug, PartialEq)]
+enum SoundMenuEntry {
+    Volume,
+    Mute,
+    NotificationSound,
+    WorkAmbience,
+    BreakAmbience,
+    Back,
+}
+
+/// Which session phase an ambience track is being chosen for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AmbienceKind {
+    Work,
+    Break,
+}
+
+/// Milliseconds between each revealed character of the typewriter overlay.
+const TYPEWRITER_CHAR_MS: u64 = 50;
+/// Ticks to hold the fully revealed message before accepting menu input.
+const TYPEWRITER_HOLD_TICKS: u32 = 5;
+
+/// Drives the character-by-character reveal of the session-complete overlay
+/// from the 200 ms tick loop. Advances [`Self::revealed`] by an elapsed number
+/// of character intervals each tick, then holds the full text for a fixed
+/// number of ticks before input is enabled.
+struct Typewriter {
+    message: String,
+    revealed: usize,
+    elapsed_ms: u64,
+    hold_ticks: u32,
+    input_enabled: bool,
+}
+
+impl Typewriter {
+    fn new(message: impl Into<String>) -> Self {
+        Typewriter {
+            message: message.into(),
+            revealed: 0,
+            elapsed_ms: 0,
+            hold_ticks: 0,
+            input_enabled: false,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.message.chars().count()
+    }
+
+    /// Advance the animation by one tick worth of time.
+    fn tick(&mut self, tick_ms: u64) {
+        if self.revealed < self.len() {
+            self.elapsed_ms += tick_ms;
+            let steps = (self.elapsed_ms / TYPEWRITER_CHAR_MS) as usize;
+            self.elapsed_ms %= TYPEWRITER_CHAR_MS;
+            self.revealed = (self.revealed + steps).min(self.len());
+        } else if self.hold_ticks < TYPEWRITER_HOLD_TICKS {
+            self.hold_ticks += 1;
+        } else {
+            self.input_enabled = true;
+        }
+    }
+
+    /// Skip the animation straight to the fully revealed, interactive state.
+    fn skip(&mut self) {
+        self.revealed = self.len();
+        self.hold_ticks = TYPEWRITER_HOLD_TICKS;
+        self.input_enabled = true;
+    }
+
+    /// The portion of the message revealed so far.
+    fn visible(&self) -> String {
+        self.message.chars().take(self.revealed).collect()
+    }
+}
+
 enum MenuState {
     None,
-    MainMenu,
-    SelectWorkDuration,
-    SelectBreakDuration,
-    ExtendWorkSession,
-    SelectSound,
+    MainMenu(Menu<MainMenuEntry>),
+    SelectWorkDuration(Menu<u64>),
+    SelectBreakDuration(Menu<u64>),
+    ExtendWorkSession(Menu<ExtendEntry>),
+    SoundMenu(Menu<SoundMenuEntry>),
+    SelectSound(Menu<PathBuf>),
+    SelectAmbience(AmbienceKind, Menu<Option<PathBuf>>),
+    SelectLanguage(Menu<String>),
+}
+
+impl MenuState {
+    fn is_open(&self) -> bool {
+        !matches!(self, MenuState::None)
+    }
 }
 
 pub struct App {
@@ -31,7 +324,9 @@ pub struct App {
     rx: mpsc::Receiver<Event>,
     hide_image: bool,
     menu_state: MenuState,
-    menu_selection: usize,
+    config: Config,
+    typewriter: Option<Typewriter>,
+    i18n: I18n,
 }
 
 impl App {
@@ -42,23 +337,60 @@ impl App {
         sound: &Path,
         no_sound: bool,
     ) -> Self {
+        // Load persisted settings, falling back to the CLI-provided values when
+        // no config file exists yet. The config file takes precedence so changes made in the
+        // menu are remembered across sessions.
+        let config = Config::load().unwrap_or(Config {
+            work_min,
+            break_min,
+            sound: sound.to_path_buf(),
+            auto_start: false,
+            volume: 100,
+            muted: false,
+            work_ambience: None,
+            break_ambience: None,
+            language: default_language(),
+        });
+
         let (tx, rx) = mpsc::channel();
+        let mut pomo = pomodoro_tui::Pomodoro::new(
+            (config.work_min, 0),
+            (config.break_min, 0),
+            config.sound.clone(),
+            no_sound,
+        );
+        if pomo.auto_start() != config.auto_start {
+            pomo.toggle_auto_start();
+        }
+        pomo.set_volume(config.effective_volume());
+        pomo.set_work_ambience(config.work_ambience.clone());
+        pomo.set_break_ambience(config.break_ambience.clone());
+
+        let i18n = I18n::load(&config.language);
+
         App {
-            pomo: pomodoro_tui::Pomodoro::new(
-                (work_min, 0),
-                (break_min, 0),
-                sound.to_path_buf(),
-                no_sound,
-            ),
+            pomo,
             exit: false,
             tx,
             rx,
             hide_image,
             menu_state: MenuState::None,
-            menu_selection: 0,
+            config,
+            typewriter: None,
+            i18n,
         }
     }
 
+    /// Translate a UI string key into the active language.
+    fn t(&self, key: &str) -> &str {
+        self.i18n.get(key)
+    }
+
+    /// Translate `key` and substitute `arg` for the `{}` placeholder.
+    fn tf(&self, key: &str, arg: &str) -> String {
+        self.t(key).replacen("{}", arg, 1)
+    }
+
     pub fn run(&mut self, mut terminal: DefaultTerminal) -> io::Result<()> {
         while !self.exit {
             terminal.draw(|frame| self.draw(frame))?;
@@ -67,8 +399,11 @@ impl App {
                 Ok(Event::Tick) => {
                     let work_session_ended = self.pomo.check_and_switch();
                     if work_session_ended {
-                        self.menu_state = MenuState::ExtendWorkSession;
-                        self.menu_selection = 0;
+                        self.menu_state = MenuState::ExtendWorkSession(self.extend_menu());
+                        self.typewriter = Some(Typewriter::new(self.t("session_complete")));
+                    }
+                    if let Some(typewriter) = &mut self.typewriter {
+                        typewriter.tick(TICK_MS);
                     }
                 }
                 _ => (),
@@ -79,7 +414,7 @@ impl App {
 
     pub fn handle_inputs(&self) {
         let tx = self.tx.clone();
-        let tick_rate = time::Duration::from_millis(200);
+        let tick_rate = time::Duration::from_millis(TICK_MS);
         std::thread::spawn(move || {
             let mut last_tick = time::Instant::now();
             loop {
@@ -135,7 +470,7 @@ impl App {
         frame.render_widget(break_timer, rbottom);
 
         // Render menu overlay if menu is active
-        if self.menu_state != MenuState::None {
+        if self.menu_state.is_open() {
             self.render_menu(frame, area);
         }
     }
@@ -172,20 +507,17 @@ impl App {
     }
 
     fn get_block_widget(&self) -> widgets::Block<'_> {
-        let start_pause = match self.pomo.is_running() {
-            true => "Pause ",
-            false => "Start ",
-        };
+        let start_pause = self.t(if self.pomo.is_running() { "pause" } else { "start" });
 
-        let title = text::Line::from(" Pomodoro ".bold());
+        let title = text::Line::from(self.t("pomodoro_title").to_string().bold());
         let instructions = text::Line::from(vec![
-            start_pause.into(),
+            start_pause.to_string().into(),
             "<S>".blue().bold(),
-            " Reset ".into(),
+            self.t("reset").to_string().into(),
             "<R>".blue().bold(),
-            " Configure ".into(),
+            self.t("configure").to_string().into(),
             "<C>".blue().bold(),
-            " Quit ".into(),
+            self.t("quit").to_string().into(),
             "<Q/Esc> ".blue().bold(),
         ]);
         widgets::Block::bordered()
@@ -225,7 +557,7 @@ impl App {
     }
 
     fn handle_key_event(&mut self, key_event: event::KeyEvent) {
-        if self.menu_state != MenuState::None {
+        if self.menu_state.is_open() {
             // Handle menu navigation
             self.handle_menu_key_event(key_event);
         } else {
@@ -238,8 +570,7 @@ impl App {
                     self.pomo.reset();
                 }
                 event::KeyCode::Char('c') => {
-                    self.menu_state = MenuState::MainMenu;
-                    self.menu_selection = 0;
+                    self.menu_state = MenuState::MainMenu(self.main_menu());
                 }
                 event::KeyCode::Esc => self.exit = true,
                 event::KeyCode::Char('q') => self.exit = true,
@@ -249,50 +580,63 @@ impl App {
     }
 
     fn handle_menu_key_event(&mut self, key_event: event::KeyEvent) {
-        match key_event.code {
-            event::KeyCode::Up => {
-                if self.menu_selection > 0 {
-                    self.menu_selection -= 1;
-                }
-            }
-            event::KeyCode::Down => {
-                let max_items = match self.menu_state {
-                    MenuState::MainMenu => 4, // 5 items (0-4)
-                    MenuState::SelectWorkDuration | MenuState::SelectBreakDuration => {
-                        DURATION_PRESETS.len() - 1
-                    }
-                    MenuState::ExtendWorkSession => DURATION_PRESETS.len(), // presets + "No, start break"
-                    MenuState::SelectSound => {
-                        let sound_count = self.get_sound_files().len();
-                        if sound_count > 0 {
-                            sound_count - 1
-                        } else {
-                            0
-                        }
-                    }
-                    MenuState::None => 0,
-                };
-                if self.menu_selection < max_items {
-                    self.menu_selection += 1;
-                }
+        // While the session-complete overlay is still animating, any key just
+        // skips to the full reveal rather than acting on the menu.
+        if let Some(typewriter) = &mut self.typewriter {
+            if !typewriter.input_enabled {
+                typewriter.skip();
+                return;
             }
+        }
+
+        match key_event.code {
+            event::KeyCode::Up => match &mut self.menu_state {
+                MenuState::MainMenu(menu) => menu.up(),
+                MenuState::SelectWorkDuration(menu) => menu.up(),
+                MenuState::SelectBreakDuration(menu) => menu.up(),
+                MenuState::ExtendWorkSession(menu) => menu.up(),
+                MenuState::SoundMenu(menu) => menu.up(),
+                MenuState::SelectSound(menu) => menu.up(),
+                MenuState::SelectAmbience(_, menu) => menu.up(),
+                MenuState::SelectLanguage(menu) => menu.up(),
+                MenuState::None => {}
+            },
+            event::KeyCode::Down => match &mut self.menu_state {
+                MenuState::MainMenu(menu) => menu.down(),
+                MenuState::SelectWorkDuration(menu) => menu.down(),
+                MenuState::SelectBreakDuration(menu) => menu.down(),
+                MenuState::ExtendWorkSession(menu) => menu.down(),
+                MenuState::SoundMenu(menu) => menu.down(),
+                MenuState::SelectSound(menu) => menu.down(),
+                MenuState::SelectAmbience(_, menu) => menu.down(),
+                MenuState::SelectLanguage(menu) => menu.down(),
+                MenuState::None => {}
+            },
+            event::KeyCode::Left => self.adjust_volume(-(VOLUME_STEP as i16)),
+            event::KeyCode::Right => self.adjust_volume(VOLUME_STEP as i16),
             event::KeyCode::Enter => {
                 self.handle_menu_selection();
             }
             event::KeyCode::Esc => {
                 // Go back or close menu
                 match self.menu_state {
-                    MenuState::MainMenu => {
+                    MenuState::MainMenu(_) => {
                         self.menu_state = MenuState::None;
                     }
-                    MenuState::SelectWorkDuration | MenuState::SelectBreakDuration | MenuState::SelectSound => {
-                        self.menu_state = MenuState::MainMenu;
-                        self.menu_selection = 0;
+                    MenuState::SelectWorkDuration(_)
+                    | MenuState::SelectBreakDuration(_)
+                    | MenuState::SoundMenu(_)
+                    | MenuState::SelectLanguage(_) => {
+                        self.menu_state = MenuState::MainMenu(self.main_menu());
                     }
-                    MenuState::ExtendWorkSession => {
+                    MenuState::SelectSound(_) | MenuState::SelectAmbience(_, _) => {
+                        self.menu_state = MenuState::SoundMenu(self.sound_settings_menu());
+                    }
+                    MenuState::ExtendWorkSession(_) => {
                         // Esc means "start break"
                         self.pomo.start_or_pause();
                         self.menu_state = MenuState::None;
+                        self.typewriter = None;
                     }
                     MenuState::None => {}
                 }
@@ -302,77 +646,256 @@ impl App {
     }
 
     fn handle_menu_selection(&mut self) {
-        match self.menu_state {
-            MenuState::MainMenu => {
-                match self.menu_selection {
-                    0 => {
-                        // Change Work Duration
-                        self.menu_state = MenuState::SelectWorkDuration;
-                        self.menu_selection = 0;
-                    }
-                    1 => {
-                        // Change Break Duration
-                        self.menu_state = MenuState::SelectBreakDuration;
-                        self.menu_selection = 0;
-                    }
-                    2 => {
-                        // Toggle Auto-Start
-                        self.pomo.toggle_auto_start();
-                        // Stay in menu to show updated state
-                    }
-                    3 => {
-                        // Change Notification Sound
-                        self.menu_state = MenuState::SelectSound;
-                        self.menu_selection = 0;
-                    }
-                    4 => {
-                        // Back
-                        self.menu_state = MenuState::None;
-                    }
-                    _ => {}
+        match &self.menu_state {
+            MenuState::MainMenu(menu) => match menu.selected() {
+                Some(MainMenuEntry::WorkDuration) => {
+                    self.menu_state = MenuState::SelectWorkDuration(self.duration_menu());
                 }
-            }
-            MenuState::SelectWorkDuration => {
-                if self.menu_selection < DURATION_PRESETS.len() {
-                    let duration = DURATION_PRESETS[self.menu_selection];
+                Some(MainMenuEntry::BreakDuration) => {
+                    self.menu_state = MenuState::SelectBreakDuration(self.duration_menu());
+                }
+                Some(MainMenuEntry::AutoStart) => {
+                    self.pomo.toggle_auto_start();
+                    self.config.auto_start = self.pomo.auto_start();
+                    self.config.save();
+                    // Rebuild so the toggled state shows, keeping the highlight.
+                    let mut menu = self.main_menu();
+                    menu.highlight(&MainMenuEntry::AutoStart);
+                    self.menu_state = MenuState::MainMenu(menu);
+                }
+                Some(MainMenuEntry::Sound) => {
+                    self.menu_state = MenuState::SoundMenu(self.sound_settings_menu());
+                }
+                Some(MainMenuEntry::Language) => {
+                    self.menu_state = MenuState::SelectLanguage(self.language_menu());
+                }
+                Some(MainMenuEntry::Back) => {
+                    self.menu_state = MenuState::None;
+                }
+                None => {}
+            },
+            MenuState::SelectWorkDuration(menu) => {
+                if let Some(&duration) = menu.selected() {
                     self.pomo.set_work_duration(duration);
-                    self.menu_state = MenuState::MainMenu;
-                    self.menu_selection = 0;
+                    self.config.work_min = duration;
+                    self.config.save();
+                    self.menu_state = MenuState::MainMenu(self.main_menu());
                 }
             }
-            MenuState::SelectBreakDuration => {
-                if self.menu_selection < DURATION_PRESETS.len() {
-                    let duration = DURATION_PRESETS[self.menu_selection];
+            MenuState::SelectBreakDuration(menu) => {
+                if let Some(&duration) = menu.selected() {
                     self.pomo.set_break_duration(duration);
-                    self.menu_state = MenuState::MainMenu;
-                    self.menu_selection = 0;
+                    self.config.break_min = duration;
+                    self.config.save();
+                    self.menu_state = MenuState::MainMenu(self.main_menu());
                 }
             }
-            MenuState::ExtendWorkSession => {
-                if self.menu_selection < DURATION_PRESETS.len() {
-                    // Extend work session
-                    let duration = DURATION_PRESETS[self.menu_selection];
-                    self.pomo.extend_work_session(duration);
+            MenuState::ExtendWorkSession(menu) => match menu.selected() {
+                Some(ExtendEntry::Extend(duration)) => {
+                    self.pomo.extend_work_session(*duration);
                     self.menu_state = MenuState::None;
-                } else {
-                    // "No, start break" option selected
+                    self.typewriter = None;
+                }
+                Some(ExtendEntry::StartBreak) => {
                     self.pomo.start_or_pause();
                     self.menu_state = MenuState::None;
+                    self.typewriter = None;
+                }
+                None => {}
+            },
+            MenuState::SoundMenu(menu) => match menu.selected() {
+                Some(SoundMenuEntry::Volume) => {
+                    // Volume is adjusted with Left/Right, not Enter.
+                }
+                Some(SoundMenuEntry::Mute) => {
+                    self.config.muted = !self.config.muted;
+                    self.pomo.set_volume(self.config.effective_volume());
+                    self.config.save();
+                    let mut menu = self.sound_settings_menu();
+                    menu.highlight(&SoundMenuEntry::Mute);
+                    self.menu_state = MenuState::SoundMenu(menu);
+                }
+                Some(SoundMenuEntry::NotificationSound) => {
+                    self.menu_state = MenuState::SelectSound(self.sound_menu());
+                }
+                Some(SoundMenuEntry::WorkAmbience) => {
+                    let menu = self.ambience_menu();
+                    self.menu_state = MenuState::SelectAmbience(AmbienceKind::Work, menu);
+                }
+                Some(SoundMenuEntry::BreakAmbience) => {
+                    let menu = self.ambience_menu();
+                    self.menu_state = MenuState::SelectAmbience(AmbienceKind::Break, menu);
+                }
+                Some(SoundMenuEntry::Back) => {
+                    self.menu_state = MenuState::MainMenu(self.main_menu());
+                }
+                None => {}
+            },
+            MenuState::SelectSound(menu) => {
+                if let Some(sound) = menu.selected() {
+                    self.pomo.set_sound(sound.clone());
+                    self.config.sound = sound.clone();
+                    self.config.save();
+                    self.menu_state = MenuState::SoundMenu(self.sound_settings_menu());
+                }
+            }
+            MenuState::SelectAmbience(kind, menu) => {
+                if let Some(selection) = menu.selected() {
+                    let track = selection.clone();
+                    match kind {
+                        AmbienceKind::Work => {
+                            self.pomo.set_work_ambience(track.clone());
+                            self.config.work_ambience = track;
+                        }
+                        AmbienceKind::Break => {
+                            self.pomo.set_break_ambience(track.clone());
+                            self.config.break_ambience = track;
+                        }
+                    }
+                    self.config.save();
+                    self.menu_state = MenuState::SoundMenu(self.sound_settings_menu());
                 }
             }
-            MenuState::SelectSound => {
-                let sound_files = self.get_sound_files();
-                if !sound_files.is_empty() && self.menu_selection < sound_files.len() {
-                    let selected_sound = sound_files[self.menu_selection].clone();
-                    self.pomo.set_sound(selected_sound);
-                    self.menu_state = MenuState::MainMenu;
-                    self.menu_selection = 0;
+            MenuState::SelectLanguage(menu) => {
+                if let Some(code) = menu.selected() {
+                    self.config.language = code.clone();
+                    self.i18n = I18n::load(code);
+                    self.config.save();
+                    self.menu_state = MenuState::MainMenu(self.main_menu());
                 }
             }
             MenuState::None => {}
         }
     }
 
+    /// Change the volume by `delta` percent, clamped to `0..=100`, but only
+    /// while the Volume row of the Sound menu is highlighted. Applies the new
+    /// gain to the sink and persists it.
+    fn adjust_volume(&mut self, delta: i16) {
+        let on_volume_row = matches!(
+            &self.menu_state,
+            MenuState::SoundMenu(menu) if menu.selected() == Some(&SoundMenuEntry::Volume)
+        );
+        if !on_volume_row {
+            return;
+        }
+        let new_volume = (self.config.volume as i16 + delta).clamp(0, 100) as u8;
+        if new_volume == self.config.volume {
+            return;
+        }
+        self.config.volume = new_volume;
+        self.pomo.set_volume(self.config.effective_volume());
+        self.config.save();
+    }
+
+    fn main_menu(&self) -> Menu<MainMenuEntry> {
+        let auto_start_status = self.t(if self.pomo.auto_start() { "on" } else { "off" });
+        Menu::new(vec![
+            MenuEntry::active(MainMenuEntry::WorkDuration, self.t("change_work")),
+            MenuEntry::active(MainMenuEntry::BreakDuration, self.t("change_break")),
+            MenuEntry::active(MainMenuEntry::AutoStart, self.tf("auto_start", auto_start_status)),
+            MenuEntry::active(MainMenuEntry::Sound, self.t("sound")),
+            MenuEntry::active(MainMenuEntry::Language, self.t("language")),
+            MenuEntry::active(MainMenuEntry::Back, self.t("back")),
+        ])
+    }
+
+    fn duration_menu(&self) -> Menu<u64> {
+        Menu::new(
+            DURATION_PRESETS
+                .iter()
+                .map(|&d| MenuEntry::active(d, self.tf("minutes", &d.to_string())))
+                .collect(),
+        )
+    }
+
+    fn extend_menu(&self) -> Menu<ExtendEntry> {
+        let mut entries: Vec<MenuEntry<ExtendEntry>> = DURATION_PRESETS
+            .iter()
+            .map(|&d| MenuEntry::active(ExtendEntry::Extend(d), self.tf("extend", &d.to_string())))
+            .collect();
+        entries.push(MenuEntry::active(ExtendEntry::StartBreak, self.t("start_break")));
+        Menu::new(entries)
+    }
+
+    fn sound_settings_menu(&self) -> Menu<SoundMenuEntry> {
+        let mute_status = self.t(if self.config.muted { "on" } else { "off" });
+        Menu::new(vec![
+            // The label is informational only; the value is drawn as a gauge.
+            MenuEntry::active(SoundMenuEntry::Volume, self.t("volume")),
+            MenuEntry::active(SoundMenuEntry::Mute, self.tf("mute", mute_status)),
+            MenuEntry::active(SoundMenuEntry::NotificationSound, self.t("change_sound")),
+            MenuEntry::active(
+                SoundMenuEntry::WorkAmbience,
+                self.tf("work_ambience", &self.ambience_label(&self.config.work_ambience)),
+            ),
+            MenuEntry::active(
+                SoundMenuEntry::BreakAmbience,
+                self.tf("break_ambience", &self.ambience_label(&self.config.break_ambience)),
+            ),
+            MenuEntry::active(SoundMenuEntry::Back, self.t("back")),
+        ])
+    }
+
+    /// List of selectable ambience tracks for either session phase, with a
+    /// leading "None" entry that turns the looping track off.
+    fn ambience_menu(&self) -> Menu<Option<PathBuf>> {
+        let mut entries = vec![MenuEntry::active(None, self.t("ambience_none").to_string())];
+        entries.extend(self.get_sound_files().into_iter().filter_map(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|label| MenuEntry::active(Some(path.clone()), label.to_string()))
+        }));
+        Menu::new(entries)
+    }
+
+    /// Menu of available UI languages, with the active one highlighted.
+    fn language_menu(&self) -> Menu<String> {
+        let mut menu = Menu::new(
+            LANGUAGES
+                .iter()
+                .map(|(code, name)| MenuEntry::active(code.to_string(), name.to_string()))
+                .collect(),
+        );
+        menu.highlight(&self.config.language);
+        menu
+    }
+
+    /// Short label for an ambience selection: the file name, or the translated
+    /// "off" marker when unset.
+    fn ambience_label(&self, track: &Option<PathBuf>) -> String {
+        match track {
+            Some(path) => path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("?")
+                .to_string(),
+            None => self.t("ambience_off").to_string(),
+        }
+    }
+
+    fn sound_menu(&self) -> Menu<PathBuf> {
+        let entries: Vec<MenuEntry<PathBuf>> = self
+            .get_sound_files()
+            .into_iter()
+            .filter_map(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|label| MenuEntry::active(path.clone(), label.to_string()))
+            })
+            .collect();
+
+        if entries.is_empty() {
+            Menu::new(vec![MenuEntry {
+                value: PathBuf::new(),
+                label: self.t("no_sounds").to_string(),
+                kind: EntryKind::Disabled,
+            }])
+        } else {
+            Menu::new(entries)
+        }
+    }
+
     fn render_menu(&self, frame: &mut Frame, area: layout::Rect) {
         // Create centered popup area
         let popup_area = self.centered_rect(60, 60, area);
@@ -380,67 +903,134 @@ impl App {
         // Clear the background
         frame.render_widget(widgets::Clear, popup_area);
 
-        match self.menu_state {
-            MenuState::MainMenu => {
-                let auto_start_status = if self.pomo.auto_start() { "ON" } else { "OFF" };
-                let auto_start_label = format!("Toggle Auto-Start ({})", auto_start_status);
-                let items = vec![
-                    "Change Work Duration",
-                    "Change Break Duration",
-                    &auto_start_label,
-                    "Change Notification Sound",
-                    "Back",
-                ];
-                self.render_menu_items(frame, popup_area, "Configuration Menu", &items);
+        match &self.menu_state {
+            MenuState::MainMenu(menu) => {
+                self.render_menu_items(frame, popup_area, self.t("config_menu"), menu);
+            }
+            MenuState::SelectWorkDuration(menu) => {
+                self.render_menu_items(frame, popup_area, self.t("select_work"), menu);
+            }
+            MenuState::SelectBreakDuration(menu) => {
+                self.render_menu_items(frame, popup_area, self.t("select_break"), menu);
             }
-            MenuState::SelectWorkDuration | MenuState::SelectBreakDuration => {
-                let title = match self.menu_state {
-                    MenuState::SelectWorkDuration => "Select Work Duration (minutes)",
-                    MenuState::SelectBreakDuration => "Select Break Duration (minutes)",
-                    _ => "",
+            MenuState::ExtendWorkSession(menu) => {
+                self.render_extend_menu(frame, popup_area, menu);
+            }
+            MenuState::SoundMenu(menu) => {
+                self.render_sound_menu(frame, popup_area, menu);
+            }
+            MenuState::SelectSound(menu) => {
+                self.render_menu_items(frame, popup_area, self.t("select_sound"), menu);
+            }
+            MenuState::SelectAmbience(kind, menu) => {
+                let title = match kind {
+                    AmbienceKind::Work => self.t("select_work_ambience"),
+                    AmbienceKind::Break => self.t("select_break_ambience"),
                 };
-                let items: Vec<String> = DURATION_PRESETS
-                    .iter()
-                    .map(|d| format!("{} minutes", d))
-                    .collect();
-                let items_refs: Vec<&str> = items.iter().map(|s| s.as_str()).collect();
-                self.render_menu_items(frame, popup_area, title, &items_refs);
+                self.render_menu_items(frame, popup_area, title, menu);
             }
-            MenuState::ExtendWorkSession => {
-                let title = "Work Session Complete! Extend?";
-                let items: Vec<String> = DURATION_PRESETS
-                    .iter()
-                    .map(|d| format!("Extend {} minutes", d))
-                    .collect();
-                let mut items_with_break = items.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-                items_with_break.push("No, start break");
-                self.render_menu_items(frame, popup_area, title, &items_with_break);
+            MenuState::SelectLanguage(menu) => {
+                self.render_menu_items(frame, popup_area, self.t("select_language"), menu);
             }
-            MenuState::SelectSound => {
-                let title = "Select Notification Sound";
-                let sound_files = self.get_sound_files();
-                let items: Vec<String> = sound_files
-                    .iter()
-                    .filter_map(|p| {
-                        p.file_name()
-                            .and_then(|name| name.to_str())
-                            .map(|s| s.to_string())
-                    })
-                    .collect();
-
-                if items.is_empty() {
-                    let empty_items = vec!["No sound files found in sounds/"];
-                    self.render_menu_items(frame, popup_area, title, &empty_items);
-                } else {
-                    let items_refs: Vec<&str> = items.iter().map(|s| s.as_str()).collect();
-                    self.render_menu_items(frame, popup_area, title, &items_refs);
+            MenuState::None => {}
+        }
+    }
+
+    /// Render the Sound submenu, drawing the Volume row as a [`widgets::Gauge`]
+    /// and the remaining rows as ordinary highlighted list lines.
+    fn render_sound_menu(&self, frame: &mut Frame, area: layout::Rect, menu: &Menu<SoundMenuEntry>) {
+        let block = widgets::Block::bordered()
+            .title(text::Line::from(self.t("sound")).centered())
+            .border_set(symbols::border::THICK);
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // One line per entry, stacked from the top of the popup.
+        let constraints: Vec<layout::Constraint> = menu
+            .entries
+            .iter()
+            .map(|_| layout::Constraint::Length(1))
+            .collect();
+        let rows = layout::Layout::vertical(constraints).split(inner);
+
+        for (i, entry) in menu.entries.iter().enumerate() {
+            let highlighted = i == menu.highlighted;
+            match entry.value {
+                SoundMenuEntry::Volume => {
+                    let label = self.tf("volume_pct", &self.config.volume.to_string());
+                    let mut gauge = widgets::Gauge::default()
+                        .ratio(self.config.volume as f64 / 100.0)
+                        .label(label);
+                    gauge = if highlighted {
+                        gauge.gauge_style(ratatui::style::Style::new().yellow())
+                    } else {
+                        gauge.gauge_style(ratatui::style::Style::new().blue())
+                    };
+                    frame.render_widget(gauge, rows[i]);
+                }
+                _ => {
+                    let content = if highlighted {
+                        text::Line::from(format!("> {}", entry.label)).yellow().bold()
+                    } else {
+                        text::Line::from(format!("  {}", entry.label))
+                    };
+                    frame.render_widget(widgets::Paragraph::new(content), rows[i]);
                 }
             }
-            MenuState::None => {}
         }
     }
 
-    fn render_menu_items(&self, frame: &mut Frame, area: layout::Rect, title: &str, items: &[&str]) {
+    /// Render the extend-session prompt with the animated typewriter message
+    /// revealed above the list of extend options.
+    fn render_extend_menu(&self, frame: &mut Frame, area: layout::Rect, menu: &Menu<ExtendEntry>) {
+        let block = widgets::Block::bordered()
+            .title(text::Line::from(self.t("extend_title")).centered())
+            .border_set(symbols::border::THICK);
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Reserve the top two lines for the revealed message, the rest for the
+        // options. The list only accepts input once the reveal has finished.
+        let [message_area, list_area] = layout::Layout::vertical([
+            layout::Constraint::Length(2),
+            layout::Constraint::Fill(1),
+        ])
+        .areas(inner);
+
+        let revealed = self
+            .typewriter
+            .as_ref()
+            .map(|t| t.visible())
+            .unwrap_or_default();
+        let message = widgets::Paragraph::new(text::Line::from(revealed.bold()))
+            .alignment(layout::Alignment::Center);
+        frame.render_widget(message, message_area);
+
+        let list_items: Vec<widgets::ListItem> = menu
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let content = if i == menu.highlighted && entry.kind == EntryKind::Active {
+                    text::Line::from(format!("> {}", entry.label)).yellow().bold()
+                } else {
+                    text::Line::from(format!("  {}", entry.label))
+                };
+                widgets::ListItem::new(content)
+            })
+            .collect();
+        frame.render_widget(widgets::List::new(list_items), list_area);
+    }
+
+    fn render_menu_items<T>(
+        &self,
+        frame: &mut Frame,
+        area: layout::Rect,
+        title: &str,
+        menu: &Menu<T>,
+    ) {
         let block = widgets::Block::bordered()
             .title(text::Line::from(title).centered())
             .border_set(symbols::border::THICK);
@@ -448,14 +1038,15 @@ impl App {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        let list_items: Vec<widgets::ListItem> = items
+        let list_items: Vec<widgets::ListItem> = menu
+            .entries
             .iter()
             .enumerate()
-            .map(|(i, item)| {
-                let content = if i == self.menu_selection {
-                    text::Line::from(format!("> {}", item)).yellow().bold()
+            .map(|(i, entry)| {
+                let content = if i == menu.highlighted && entry.kind == EntryKind::Active {
+                    text::Line::from(format!("> {}", entry.label)).yellow().bold()
                 } else {
-                    text::Line::from(format!("  {}", item))
+                    text::Line::from(format!("  {}", entry.label))
                 };
                 widgets::ListItem::new(content)
             })